@@ -0,0 +1,139 @@
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, LINK, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+use crate::common::{
+    build_url, endpoint_map, parse_link_next, requires_auth, validate_provision_data, Auth,
+    BASE_URL,
+};
+use crate::error::{ApiError, WebdockError};
+use crate::models::Server;
+
+/// Async counterpart to [`crate::client::Webdock`], built on `reqwest`'s
+/// non-blocking client for callers already running inside a Tokio runtime.
+pub struct AsyncWebdock {
+    base_url: String,
+    endpoints: HashMap<&'static str, &'static str>,
+    client: Client,
+    auth: Auth,
+}
+
+impl AsyncWebdock {
+    /// Build a client. Pass `None` to use only the public, read-only
+    /// endpoints without a token; of this client's methods, only `ping`
+    /// works without one.
+    pub fn new(api_token: Option<&str>) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            "X-Client",
+            HeaderValue::from_static("webdock-rust-sdk/v1.0.0"),
+        );
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let auth = match api_token {
+            Some(token) => Auth::Authorized(token.to_string()),
+            None => Auth::Unauthorized,
+        };
+
+        AsyncWebdock {
+            base_url: String::from(BASE_URL),
+            endpoints: endpoint_map(),
+            client,
+            auth,
+        }
+    }
+
+    /// Attach the `Authorization` header to `builder` if `endpoint_key` needs
+    /// one, failing fast if the client was built without a token.
+    fn authorize(&self, builder: RequestBuilder, endpoint_key: &str) -> Result<RequestBuilder, WebdockError> {
+        if !requires_auth(endpoint_key) {
+            return Ok(builder);
+        }
+
+        match &self.auth {
+            Auth::Authorized(token) => Ok(builder.bearer_auth(token)),
+            Auth::Unauthorized => Err(WebdockError::ValidationException(String::from(
+                "token required",
+            ))),
+        }
+    }
+
+    async fn send_response<T: DeserializeOwned>(&self, res: Response) -> Result<T, WebdockError> {
+        let status = res.status().as_u16();
+        let retry_after = res
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+
+        match status {
+            200 | 201 | 202 | 418 => Ok(res.json().await?),
+            429 => Err(WebdockError::RateLimited { retry_after }),
+            status_code => match res.json::<ApiError>().await {
+                Ok(ApiError { message, id }) => Err(WebdockError::Api {
+                    status: status_code,
+                    message,
+                    id,
+                    retry_after,
+                }),
+                Err(_) => Err(WebdockError::Api {
+                    status: status_code,
+                    message: String::from("unknown error"),
+                    id: None,
+                    retry_after,
+                }),
+            },
+        }
+    }
+
+    pub async fn ping(&self) -> Result<serde_json::Value, WebdockError> {
+        let builder = self.authorize(
+            self.client.get(build_url(&self.base_url, self.endpoints["ping"])),
+            "ping",
+        )?;
+        let res = builder.send().await?;
+        self.send_response(res).await
+    }
+
+    /// Fetch the servers list, transparently following `Link: rel="next"`
+    /// pagination until every page has been collected.
+    pub async fn servers(&self) -> Result<Vec<Server>, WebdockError> {
+        let mut items = Vec::new();
+        let mut url = build_url(&self.base_url, self.endpoints["servers"]);
+
+        loop {
+            let builder = self.authorize(self.client.get(&url), "servers")?;
+            let res = builder.send().await?;
+            let next = res
+                .headers()
+                .get(LINK)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_link_next);
+            let page: Vec<Server> = self.send_response(res).await?;
+            items.extend(page);
+
+            match next {
+                Some(next_url) => url = next_url,
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    pub async fn provision_server(&self, data: &serde_json::Value) -> Result<Server, WebdockError> {
+        validate_provision_data(data)?;
+        let builder = self.authorize(
+            self.client.post(build_url(&self.base_url, self.endpoints["servers"])),
+            "servers",
+        )?;
+        let res = builder.json(data).send().await?;
+        self.send_response(res).await
+    }
+}
@@ -0,0 +1,419 @@
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, LINK, RETRY_AFTER};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::common::{
+    build_url, endpoint_map, parse_link_next, requires_auth, validate_provision_data, Auth,
+    BASE_URL,
+};
+use crate::error::{ApiError, WebdockError};
+use crate::models::{Event, Hook, Image, Location, PageInfo, Profile, PublicKey, ScriptEntry, Server};
+
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Exponential-backoff retry policy applied to idempotent requests.
+struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+fn is_retryable(err: &WebdockError) -> bool {
+    match err {
+        WebdockError::RateLimited { .. } => true,
+        WebdockError::Api { status: 503, .. } => true,
+        WebdockError::ReqwestError(e) => e.is_timeout() || e.is_connect(),
+        _ => false,
+    }
+}
+
+fn retry_after_secs(err: &WebdockError) -> Option<u64> {
+    match err {
+        WebdockError::RateLimited { retry_after } => *retry_after,
+        WebdockError::Api { retry_after, .. } => *retry_after,
+        _ => None,
+    }
+}
+
+/// Compute the delay before the next retry attempt (0-indexed), honoring a
+/// server-supplied `Retry-After` value over the computed backoff, and adding
+/// a small jitter so retrying clients don't all wake up at once.
+fn backoff_delay(base_delay: Duration, attempt: u32, retry_after: Option<u64>) -> Duration {
+    if let Some(secs) = retry_after {
+        return Duration::from_secs(secs);
+    }
+
+    let exp = base_delay.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_BACKOFF);
+    let jitter_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_millis() % 250)
+        .unwrap_or(0);
+    exp + Duration::from_millis(jitter_ms as u64)
+}
+
+pub struct Webdock {
+    base_url: String,
+    endpoints: HashMap<&'static str, &'static str>,
+    client: Client,
+    retry_policy: Option<RetryPolicy>,
+    auth: Auth,
+}
+
+impl Webdock {
+    /// Build a client. Pass `None` to use only the public, read-only
+    /// endpoints (`ping`, `locations`, `profiles`, `images`) without a token.
+    pub fn new(api_token: Option<&str>) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            "X-Client",
+            HeaderValue::from_static("webdock-rust-sdk/v1.0.0"),
+        );
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let auth = match api_token {
+            Some(token) => Auth::Authorized(token.to_string()),
+            None => Auth::Unauthorized,
+        };
+
+        Webdock {
+            base_url: String::from(BASE_URL),
+            endpoints: endpoint_map(),
+            client,
+            retry_policy: None,
+            auth,
+        }
+    }
+
+    /// Retry idempotent requests (GET/DELETE, plus POSTs explicitly marked
+    /// retriable) up to `max_retries` times on connection errors, timeouts,
+    /// and 429/503 responses, backing off exponentially from `base_delay`.
+    pub fn with_retries(mut self, max_retries: u32, base_delay: Duration) -> Self {
+        self.retry_policy = Some(RetryPolicy {
+            max_retries,
+            base_delay,
+        });
+        self
+    }
+
+    /// Attach the `Authorization` header to `builder` if `endpoint_key` needs
+    /// one, failing fast if the client was built without a token.
+    fn authorize(&self, builder: RequestBuilder, endpoint_key: &str) -> Result<RequestBuilder, WebdockError> {
+        if !requires_auth(endpoint_key) {
+            return Ok(builder);
+        }
+
+        match &self.auth {
+            Auth::Authorized(token) => Ok(builder.bearer_auth(token)),
+            Auth::Unauthorized => Err(WebdockError::ValidationException(String::from(
+                "token required",
+            ))),
+        }
+    }
+
+    fn send_response<T: DeserializeOwned>(&self, res: Response) -> Result<T, WebdockError> {
+        let status = res.status().as_u16();
+        let retry_after = res
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+
+        match status {
+            200 | 201 | 202 | 418 => Ok(res.json()?),
+            429 => Err(WebdockError::RateLimited { retry_after }),
+            status_code => match res.json::<ApiError>() {
+                Ok(ApiError { message, id }) => Err(WebdockError::Api {
+                    status: status_code,
+                    message,
+                    id,
+                    retry_after,
+                }),
+                Err(_) => Err(WebdockError::Api {
+                    status: status_code,
+                    message: String::from("unknown error"),
+                    id: None,
+                    retry_after,
+                }),
+            },
+        }
+    }
+
+    fn make_request_once<T: DeserializeOwned>(
+        &self,
+        endpoint_key: &str,
+        request_type: &str,
+        data: Option<&serde_json::Value>,
+    ) -> Result<T, WebdockError> {
+        let endpoint = self.endpoints[endpoint_key];
+        match request_type {
+            "GET" => {
+                let builder = self.authorize(self.client.get(build_url(&self.base_url, endpoint)), endpoint_key)?;
+                self.send_response(builder.send()?)
+            }
+            "POST" | "PATCH" => {
+                let builder = self.authorize(self.client.post(build_url(&self.base_url, endpoint)), endpoint_key)?;
+                self.send_response(builder.json(data.expect("REASON")).send()?)
+            }
+            "DELETE" => {
+                let builder = self.authorize(self.client.delete(build_url(&self.base_url, endpoint)), endpoint_key)?;
+                self.send_response(builder.send()?)
+            }
+            _ => Err(WebdockError::ValidationException(String::from(
+                "Unsupported request type",
+            ))),
+        }
+    }
+
+    /// Run `attempt_fn`, retrying on transient failures when a retry policy is
+    /// configured and `retriable` is true. Shared by every request path (plain
+    /// requests, paginated list fetches) so a configured policy is honored
+    /// everywhere, not just through [`Webdock::make_request`].
+    fn with_retry<T>(
+        &self,
+        retriable: bool,
+        mut attempt_fn: impl FnMut() -> Result<T, WebdockError>,
+    ) -> Result<T, WebdockError> {
+        let mut attempt = 0;
+
+        loop {
+            match attempt_fn() {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let policy = match &self.retry_policy {
+                        Some(policy) if retriable => policy,
+                        _ => return Err(err),
+                    };
+
+                    if attempt >= policy.max_retries || !is_retryable(&err) {
+                        return Err(err);
+                    }
+
+                    sleep(backoff_delay(policy.base_delay, attempt, retry_after_secs(&err)));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Issue a request, retrying on transient failures when a retry policy is
+    /// configured. GET/DELETE are always retried whatever `retriable` says;
+    /// mutating verbs are retried only when `retriable` is true, which callers
+    /// should pass only when the request is safe to repeat.
+    fn make_request<T: DeserializeOwned>(
+        &self,
+        endpoint_key: &str,
+        request_type: &str,
+        data: Option<&serde_json::Value>,
+        retriable: bool,
+    ) -> Result<T, WebdockError> {
+        let retriable = retriable || matches!(request_type, "GET" | "DELETE");
+        self.with_retry(retriable, || {
+            self.make_request_once(endpoint_key, request_type, data)
+        })
+    }
+
+    /// Fetch a GET list endpoint, transparently following `Link: rel="next"`
+    /// pagination until every page has been collected.
+    fn get_all_pages<T: DeserializeOwned>(&self, endpoint_key: &str) -> Result<Vec<T>, WebdockError> {
+        let mut items = Vec::new();
+        let mut url = build_url(&self.base_url, self.endpoints[endpoint_key]);
+
+        loop {
+            let (page, next): (Vec<T>, Option<String>) = self.with_retry(true, || {
+                let builder = self.authorize(self.client.get(&url), endpoint_key)?;
+                let res = builder.send()?;
+                let next = res
+                    .headers()
+                    .get(LINK)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(parse_link_next);
+                let page: Vec<T> = self.send_response(res)?;
+                Ok((page, next))
+            })?;
+            items.extend(page);
+
+            match next {
+                Some(next_url) => url = next_url,
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Fetch a single page of a GET list endpoint without following pagination,
+    /// returning the page alongside its `PageInfo` cursor for manual paging.
+    fn get_page<T: DeserializeOwned>(
+        &self,
+        endpoint_key: &str,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<T>, PageInfo), WebdockError> {
+        let url = format!(
+            "{}?page={}&per_page={}",
+            build_url(&self.base_url, self.endpoints[endpoint_key]),
+            page,
+            per_page
+        );
+
+        self.with_retry(true, || {
+            let builder = self.authorize(self.client.get(&url), endpoint_key)?;
+            let res = builder.send()?;
+
+            let total = res
+                .headers()
+                .get("x-total-count")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok());
+            let next = res
+                .headers()
+                .get(LINK)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_link_next);
+
+            let data: Vec<T> = self.send_response(res)?;
+            Ok((data, PageInfo { total, next }))
+        })
+    }
+
+    pub fn ping(&self) -> Result<serde_json::Value, WebdockError> {
+        self.make_request("ping", "GET", None, true)
+    }
+
+    /// List datacenter locations. Works without a token.
+    pub fn locations(&self) -> Result<Vec<Location>, WebdockError> {
+        self.get_all_pages("locations")
+    }
+
+    /// List hardware profiles available at provisioning time. Works without a token.
+    pub fn profiles(&self) -> Result<Vec<Profile>, WebdockError> {
+        self.get_all_pages("profiles")
+    }
+
+    /// List server images available at provisioning time. Works without a token.
+    pub fn images(&self) -> Result<Vec<Image>, WebdockError> {
+        self.get_all_pages("images")
+    }
+
+    pub fn servers(&self) -> Result<Vec<Server>, WebdockError> {
+        self.get_all_pages("servers")
+    }
+
+    /// Fetch a single page of the servers list without following pagination.
+    pub fn servers_page(&self, page: u32, per_page: u32) -> Result<(Vec<Server>, PageInfo), WebdockError> {
+        self.get_page("servers", page, per_page)
+    }
+
+    pub fn events(&self) -> Result<Vec<Event>, WebdockError> {
+        self.get_all_pages("events")
+    }
+
+    /// List SSH public keys registered on the account.
+    pub fn pubkeys(&self) -> Result<Vec<PublicKey>, WebdockError> {
+        self.get_all_pages("pubkeys")
+    }
+
+    /// List saved provisioning/deployment scripts.
+    pub fn scripts(&self) -> Result<Vec<ScriptEntry>, WebdockError> {
+        self.get_all_pages("scripts")
+    }
+
+    /// List webhooks registered to fire on account events.
+    pub fn hooks(&self) -> Result<Vec<Hook>, WebdockError> {
+        self.get_all_pages("hooks")
+    }
+
+    /// POST to `endpoint_key`, additionally extracting the `callbackId`/`Location`
+    /// header Webdock attaches to long-running (202) operations.
+    fn post_with_callback<T: DeserializeOwned>(
+        &self,
+        endpoint_key: &str,
+        data: &serde_json::Value,
+    ) -> Result<(T, Option<String>), WebdockError> {
+        let builder = self.authorize(
+            self.client.post(build_url(&self.base_url, self.endpoints[endpoint_key])),
+            endpoint_key,
+        )?;
+        let res = builder.json(data).send()?;
+
+        let callback_id = res
+            .headers()
+            .get("callbackId")
+            .or_else(|| res.headers().get("Location"))
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.rsplit('/').next().unwrap_or(v).to_string());
+
+        let parsed: T = self.send_response(res)?;
+        Ok((parsed, callback_id))
+    }
+
+    /// Poll the events endpoint until the operation identified by `callback_id`
+    /// reaches a terminal status, or `timeout` elapses.
+    pub fn wait_for_event(&self, callback_id: &str, timeout: Duration) -> Result<Event, WebdockError> {
+        let poll_interval = Duration::from_secs(2);
+        let start = Instant::now();
+
+        loop {
+            let event = self
+                .events()?
+                .into_iter()
+                .find(|event| event.callback_id.as_deref() == Some(callback_id));
+
+            if let Some(event) = event {
+                match event.status.as_str() {
+                    "finished" => return Ok(event),
+                    "error" => {
+                        return Err(WebdockError::WebdockException(format!(
+                            "event {} failed",
+                            callback_id
+                        )))
+                    }
+                    _ => {}
+                }
+            }
+
+            if start.elapsed() >= timeout {
+                return Err(WebdockError::WebdockException(format!(
+                    "timed out waiting for event {}",
+                    callback_id
+                )));
+            }
+
+            sleep(poll_interval);
+        }
+    }
+
+    pub fn provision_server(&self, data: &serde_json::Value) -> Result<Server, WebdockError> {
+        validate_provision_data(data)?;
+        // Not retried by default: retrying a POST risks provisioning twice.
+        self.make_request("servers", "POST", Some(data), false)
+    }
+
+    /// Like [`Webdock::provision_server`], but when Webdock's response carries
+    /// a `callbackId`/`Location` header, blocks until that provisioning event
+    /// finishes (or `timeout` elapses) before returning. Without such a header
+    /// it returns at once. The returned `Server` is the one parsed from the
+    /// POST response; it is not re-fetched after the event finishes.
+    pub fn provision_server_blocking(
+        &self,
+        data: &serde_json::Value,
+        timeout: Duration,
+    ) -> Result<Server, WebdockError> {
+        validate_provision_data(data)?;
+        let (server, callback_id) = self.post_with_callback::<Server>("servers", data)?;
+
+        if let Some(callback_id) = callback_id {
+            self.wait_for_event(&callback_id, timeout)?;
+        }
+
+        Ok(server)
+    }
+    // Add other methods following similar patterns as above...
+}
@@ -0,0 +1,77 @@
+//! Logic shared between the blocking [`crate::client::Webdock`] and the async
+//! [`crate::async_client::AsyncWebdock`] clients, so endpoint routing and
+//! request validation stay in one place.
+
+use std::collections::HashMap;
+
+use crate::error::WebdockError;
+
+pub(crate) const BASE_URL: &str = "https://api.webdock.io/v1";
+
+pub(crate) fn endpoint_map() -> HashMap<&'static str, &'static str> {
+    [
+        ("ping", "ping"),
+        ("servers", "servers"),
+        ("locations", "locations"),
+        ("profiles", "profiles"),
+        ("images", "images"),
+        ("pubkeys", "account/publicKeys"),
+        ("scripts", "scripts"),
+        ("hooks", "hooks"),
+        ("events", "events"),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+pub(crate) fn build_url(base_url: &str, endpoint: &str) -> String {
+    format!("{}/{}", base_url, endpoint)
+}
+
+/// Whether calls to this endpoint require an authenticated client. Webdock's
+/// read-only catalog endpoints (ping, locations, profiles, images) work
+/// without a token; everything account-specific does not.
+pub(crate) fn requires_auth(endpoint_key: &str) -> bool {
+    !matches!(endpoint_key, "ping" | "locations" | "profiles" | "images")
+}
+
+/// Per-client authorization mode, mirroring how many API clients distinguish
+/// calls that need a bearer token from ones that don't.
+pub(crate) enum Auth {
+    Authorized(String),
+    Unauthorized,
+}
+
+/// Parse an RFC 5988 `Link` header and return the `rel="next"` URL, if any.
+///
+/// Expects entries of the form `<url>; rel="next"`, comma-separated.
+pub(crate) fn parse_link_next(link_header: &str) -> Option<String> {
+    link_header.split(',').find_map(|entry| {
+        let mut parts = entry.split(';').map(str::trim);
+        let url_part = parts.next()?;
+        let is_next = parts.any(|param| param.replace('"', "") == "rel=next");
+        if is_next {
+            Some(url_part.trim_start_matches('<').trim_end_matches('>').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+pub(crate) fn validate_provision_data(data: &serde_json::Value) -> Result<(), WebdockError> {
+    let required_fields = ["name", "slug", "locationId", "profileSlug", "imageSlug"];
+    let data_obj = data
+        .as_object()
+        .ok_or_else(|| WebdockError::ValidationException(String::from("Invalid data format")))?;
+
+    for field in &required_fields {
+        if !data_obj.contains_key(*field) {
+            return Err(WebdockError::ValidationException(format!(
+                "Required field {} is missing.",
+                field
+            )));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub enum WebdockError {
+    ReqwestError(reqwest::Error),
+    WebdockException(String),
+    ValidationException(String),
+    Api {
+        status: u16,
+        message: String,
+        id: Option<String>,
+        retry_after: Option<u64>,
+    },
+    RateLimited {
+        retry_after: Option<u64>,
+    },
+}
+
+impl From<reqwest::Error> for WebdockError {
+    fn from(err: reqwest::Error) -> Self {
+        WebdockError::ReqwestError(err)
+    }
+}
+
+/// Shape of the JSON body Webdock returns alongside non-2xx status codes.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ApiError {
+    pub message: String,
+    pub id: Option<String>,
+}
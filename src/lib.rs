@@ -0,0 +1,5 @@
+pub mod async_client;
+pub mod client;
+pub mod common;
+pub mod error;
+pub mod models;
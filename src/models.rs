@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+/// A provisioned Webdock server instance.
+#[derive(Debug, Deserialize)]
+pub struct Server {
+    pub id: u64,
+    pub slug: String,
+    pub name: String,
+    pub status: String,
+    pub date: String,
+    #[serde(rename = "locationId")]
+    pub location_id: String,
+    #[serde(rename = "profileSlug")]
+    pub profile_slug: String,
+    #[serde(rename = "imageSlug")]
+    pub image_slug: String,
+    #[serde(rename = "publicIp")]
+    pub public_ip: Option<String>,
+}
+
+/// A datacenter location servers can be provisioned into.
+#[derive(Debug, Deserialize)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+}
+
+/// A hardware profile (RAM/CPU/disk allotment) available at provisioning time.
+#[derive(Debug, Deserialize)]
+pub struct Profile {
+    pub slug: String,
+    pub name: String,
+    pub ram: Option<u64>,
+    pub disk: Option<u64>,
+}
+
+/// A server image (OS/application template) available at provisioning time.
+#[derive(Debug, Deserialize)]
+pub struct Image {
+    pub slug: String,
+    pub name: String,
+}
+
+/// An SSH public key registered on the account.
+#[derive(Debug, Deserialize)]
+pub struct PublicKey {
+    pub id: u64,
+    pub name: String,
+    pub key: String,
+}
+
+/// A saved provisioning/deployment script.
+#[derive(Debug, Deserialize)]
+pub struct ScriptEntry {
+    pub id: u64,
+    pub name: String,
+    pub content: Option<String>,
+}
+
+/// A webhook registered to fire on account events.
+#[derive(Debug, Deserialize)]
+pub struct Hook {
+    pub id: u64,
+    pub url: String,
+    pub event: String,
+}
+
+/// Pagination metadata accompanying a single page of a list endpoint.
+#[derive(Debug)]
+pub struct PageInfo {
+    pub total: Option<u64>,
+    pub next: Option<String>,
+}
+
+/// A record of an asynchronous operation (e.g. provisioning a server).
+#[derive(Debug, Deserialize)]
+pub struct Event {
+    pub id: u64,
+    #[serde(rename = "callbackId")]
+    pub callback_id: Option<String>,
+    pub action: String,
+    pub status: String,
+}